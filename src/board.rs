@@ -0,0 +1,192 @@
+// reversi_client/src/board.rs
+//
+// Pure board logic shared by `ReversiClient` and the bundled strategies:
+// move generation, move application, and rendering.
+
+pub const DIRECTIONS: [(i8, i8); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Returns every empty square on which `player` could legally place a disc,
+/// i.e. every square that flanks at least one line of the opponent's discs.
+pub fn valid_moves(board: &[[i8; 8]; 8], player: i8) -> Vec<(i8, i8)> {
+    let mut moves: Vec<(i8, i8)> = Vec::with_capacity(24);
+    let opponent = 3 - player;
+
+    for (i, row) in board.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell != 0 {
+                continue;
+            }
+
+            'directions: for &(dx, dy) in &DIRECTIONS {
+                let mut x = i as i8 + dx;
+                let mut y = j as i8 + dy;
+                let mut found_opponent = false;
+
+                while (0..8).contains(&x) && (0..8).contains(&y) {
+                    let current = board[x as usize][y as usize];
+
+                    match current {
+                        // Empty space, can't flank
+                        0 => break,
+                        // Found player's piece after opponent's
+                        p if p == player => {
+                            if found_opponent {
+                                moves.push((i as i8, j as i8));
+                                break 'directions;
+                            }
+                            break;
+                        }
+                        // Found opponent's piece
+                        p if p == opponent => {
+                            found_opponent = true;
+                        }
+                        _ => break,
+                    }
+
+                    x += dx;
+                    y += dy;
+                }
+            }
+        }
+    }
+
+    moves
+}
+
+/// Plays `mv` for `player`, flipping every opponent disc that the move
+/// flanks. Assumes `mv` is a legal move for `player` (see [`valid_moves`]).
+pub fn apply_move(board: &mut [[i8; 8]; 8], player: i8, mv: (i8, i8)) {
+    let opponent = 3 - player;
+    let (row, col) = mv;
+
+    board[row as usize][col as usize] = player;
+
+    for &(dx, dy) in &DIRECTIONS {
+        let mut x = row + dx;
+        let mut y = col + dy;
+        let mut to_flip: Vec<(i8, i8)> = Vec::new();
+
+        while (0..8).contains(&x) && (0..8).contains(&y) {
+            let current = board[x as usize][y as usize];
+
+            match current {
+                p if p == opponent => to_flip.push((x, y)),
+                p if p == player => {
+                    for (fx, fy) in to_flip {
+                        board[fx as usize][fy as usize] = player;
+                    }
+                    break;
+                }
+                _ => break,
+            }
+
+            x += dx;
+            y += dy;
+        }
+    }
+}
+
+/// Renders `board` as an 8x8 grid with Unicode disc glyphs, marking each of
+/// `legal_moves` with a placeholder so the player can see where they're
+/// allowed to play. Rows and columns are labeled 0-7, matching the
+/// 0-indexed `(row, col)` coordinates `valid_moves` and `apply_move` use,
+/// so a label on screen is exactly what should be typed back in.
+pub fn render(board: &[[i8; 8]; 8], legal_moves: &[(i8, i8)]) -> String {
+    let mut out = String::from("  0 1 2 3 4 5 6 7\n");
+
+    for (i, row) in board.iter().enumerate() {
+        out.push_str(&format!("{} ", i));
+
+        for (j, &cell) in row.iter().enumerate() {
+            let glyph = match cell {
+                1 => '●',
+                2 => '○',
+                _ if legal_moves.contains(&(i as i8, j as i8)) => '·',
+                _ => '.',
+            };
+            out.push(glyph);
+            out.push(' ');
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_moves() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        let moves = valid_moves(&board, 1);
+
+        assert_eq!(moves, vec![(2, 3), (3, 2), (4, 5), (5, 4)]);
+    }
+
+    #[test]
+    fn test_apply_move_flips_single_direction() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        apply_move(&mut board, 1, (2, 3));
+
+        assert_eq!(board[2][3], 1);
+        assert_eq!(board[3][3], 1);
+        assert_eq!(board[4][3], 1);
+    }
+
+    #[test]
+    fn test_apply_move_flips_multiple_directions() {
+        let mut board = [[0; 8]; 8];
+        // A cluster of player 2 discs around the move, each flanked on the
+        // far side by a player 1 disc.
+        board[4][3] = 2;
+        board[4][2] = 2;
+        board[4][1] = 1;
+        board[3][4] = 2;
+        board[2][4] = 1;
+
+        apply_move(&mut board, 1, (4, 4));
+
+        assert_eq!(board[4][3], 1);
+        assert_eq!(board[4][2], 1);
+        assert_eq!(board[3][4], 1);
+    }
+
+    #[test]
+    fn test_render_marks_discs_and_legal_moves() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        let legal_moves = valid_moves(&board, 1);
+        let rendered = render(&board, &legal_moves);
+
+        assert!(rendered.contains('●'));
+        assert!(rendered.contains('○'));
+        assert!(rendered.contains('·'));
+        assert_eq!(rendered.lines().count(), 9);
+    }
+}
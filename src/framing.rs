@@ -0,0 +1,130 @@
+// reversi_client/src/framing.rs
+//
+// Buffers bytes read from the socket and extracts one complete message at
+// a time. `TcpStream::read` gives no guarantee that a read lines up with a
+// message boundary: a board can arrive split across two reads, or two
+// boards can arrive back-to-back in a single read.
+
+/// A frame is either the `-999` game-over sentinel line, or a full
+/// turn/round/clocks/board block (4 header lines plus 64 board lines).
+const TURN_STATE_LINES: usize = 4 + 64;
+const GAME_OVER_SENTINEL: &str = "-999";
+
+/// Drains one complete line (up to and including its trailing `\n`) from
+/// `buffer`, if one is available, leaving any leftover bytes in place for
+/// the next call. Used for the one-line handshake, which doesn't follow
+/// the turn-state frame shape `next_frame` expects.
+pub fn next_line(buffer: &mut Vec<u8>) -> Option<String> {
+    let end = buffer.iter().position(|&byte| byte == b'\n')?;
+    let line = String::from_utf8_lossy(&buffer[..end]).into_owned();
+    buffer.drain(..=end);
+    Some(line)
+}
+
+/// Drains one complete frame from `buffer`, if one is available, leaving
+/// any leftover bytes in place for the next call.
+pub fn next_frame(buffer: &mut Vec<u8>) -> Option<String> {
+    let newlines: Vec<usize> = buffer
+        .iter()
+        .enumerate()
+        .filter(|&(_, &byte)| byte == b'\n')
+        .map(|(i, _)| i)
+        .collect();
+
+    let first_line_end = *newlines.first()?;
+    let first_line = String::from_utf8_lossy(&buffer[..first_line_end]).into_owned();
+
+    if first_line.trim() == GAME_OVER_SENTINEL {
+        buffer.drain(..=first_line_end);
+        return Some(first_line);
+    }
+
+    let frame_end = *newlines.get(TURN_STATE_LINES - 1)?;
+    let frame = String::from_utf8_lossy(&buffer[..frame_end]).into_owned();
+    buffer.drain(..=frame_end);
+    Some(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn_state_message() -> String {
+        let mut message = String::from("1\n0\n0.0\n0.0\n");
+        message.push_str(&"0\n".repeat(64));
+        message
+    }
+
+    #[test]
+    fn test_next_line_waits_for_newline() {
+        let mut buffer = b"1 10.0".to_vec();
+
+        assert!(next_line(&mut buffer).is_none());
+    }
+
+    #[test]
+    fn test_next_line_leaves_trailing_bytes_for_next_call() {
+        let mut buffer = b"1 10.0\n".to_vec();
+        buffer.extend_from_slice(b"1\n0\n0.0\n");
+
+        let line = next_line(&mut buffer).unwrap();
+
+        assert_eq!(line, "1 10.0");
+        assert_eq!(buffer, b"1\n0\n0.0\n");
+    }
+
+    #[test]
+    fn test_next_frame_waits_for_full_message() {
+        let mut buffer = b"1\n0\n0.0\n".to_vec();
+
+        assert!(next_frame(&mut buffer).is_none());
+    }
+
+    #[test]
+    fn test_next_frame_single_message() {
+        let message = turn_state_message();
+        let mut buffer = message.as_bytes().to_vec();
+
+        let frame = next_frame(&mut buffer).unwrap();
+
+        assert_eq!(frame, message.trim_end());
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_next_frame_leaves_trailing_bytes_for_next_call() {
+        let message = turn_state_message();
+        let mut buffer = message.as_bytes().to_vec();
+        buffer.extend_from_slice(b"1\n0\n0.0\n");
+
+        let frame = next_frame(&mut buffer).unwrap();
+
+        assert_eq!(frame, message.trim_end());
+        assert_eq!(buffer, b"1\n0\n0.0\n");
+    }
+
+    #[test]
+    fn test_next_frame_game_over_sentinel() {
+        let mut buffer = b"-999\n".to_vec();
+
+        let frame = next_frame(&mut buffer).unwrap();
+
+        assert_eq!(frame, "-999");
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_next_frame_coalesced_messages() {
+        let message = turn_state_message();
+        let mut buffer = message.as_bytes().to_vec();
+        buffer.extend_from_slice(message.as_bytes());
+
+        let first = next_frame(&mut buffer).unwrap();
+        assert_eq!(first, message.trim_end());
+
+        let second = next_frame(&mut buffer).unwrap();
+        assert_eq!(second, message.trim_end());
+
+        assert!(buffer.is_empty());
+    }
+}
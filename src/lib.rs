@@ -1,11 +1,23 @@
 // reversi_client/src/lib.rs
 
-use rand::Rng;
+use std::fmt;
 use std::io::{Read, Write};
 use std::net::{SocketAddr, TcpStream};
 use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 
+mod board;
+mod framing;
+mod protocol;
+mod strategy;
+
+pub use board::apply_move;
+pub use protocol::{Packet, ProtocolError};
+pub use strategy::{
+    AlphaBetaStrategy, HumanStrategy, IterativeDeepeningStrategy, RandomStrategy, ReversiStrategy,
+};
+
 #[derive(Debug, Error)]
 pub enum ReversiError {
     #[error("Connection error: {0}")]
@@ -13,7 +25,7 @@ pub enum ReversiError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     #[error("Protocol error: {0}")]
-    ProtocolError(String),
+    ProtocolError(#[from] ProtocolError),
 }
 
 pub struct GameState {
@@ -24,16 +36,10 @@ pub struct GameState {
     pub board: [[i8; 8]; 8], // 0: empty, 1: player1, 2: player2
 }
 
-pub trait ReversiStrategy {
-    fn choose_move(&self, valid_moves: &[(i8, i8)]) -> (i8, i8);
-}
-
-pub struct RandomStrategy;
-
-impl ReversiStrategy for RandomStrategy {
-    fn choose_move(&self, valid_moves: &[(i8, i8)]) -> (i8, i8) {
-        let mut rng = rand::thread_rng();
-        valid_moves[rng.gen_range(0..valid_moves.len())]
+impl fmt::Display for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let legal_moves = board::valid_moves(&self.board, self.turn);
+        write!(f, "{}", board::render(&self.board, &legal_moves))
     }
 }
 
@@ -43,6 +49,7 @@ pub struct ReversiClient<S: ReversiStrategy> {
     strategy: S,
     game_minutes: f32,
     board: [[i8; 8]; 8],
+    inbound: Vec<u8>,
 }
 
 impl<S: ReversiStrategy> ReversiClient<S> {
@@ -56,25 +63,36 @@ impl<S: ReversiStrategy> ReversiClient<S> {
             .map_err(|e| ReversiError::ConnectionError(e.to_string()))?;
 
         let mut stream = TcpStream::connect(addr)?;
+        let mut inbound: Vec<u8> = Vec::new();
 
-        let mut buffer = [0u8; 1024];
-        let mut bytes_read = stream.read(&mut buffer)?;
-
-        while bytes_read == 0 {
-            bytes_read = stream.read(&mut buffer)?;
-        }
+        let handshake_line = loop {
+            if let Some(line) = framing::next_line(&mut inbound) {
+                break line;
+            }
 
-        let message = String::from_utf8_lossy(&buffer[..bytes_read]);
+            let mut chunk = [0u8; 1024];
+            let bytes_read = stream.read(&mut chunk)?;
+            if bytes_read == 0 {
+                return Err(ReversiError::ConnectionError("Connection closed".into()));
+            }
+            inbound.extend_from_slice(&chunk[..bytes_read]);
+        };
 
-        let parts: Vec<&str> = message.split(' ').collect();
-        println!("{:?}", parts);
-        let server_player_number = parts[0].parse().unwrap_or(-1);
-        let game_minutes = parts[1].trim().parse::<f32>().unwrap_or(0.0);
+        let (server_player_number, game_minutes) = match Packet::decode(&handshake_line)? {
+            Packet::Handshake { player, minutes } => (player, minutes),
+            other => {
+                return Err(ReversiError::ProtocolError(ProtocolError::UnexpectedPacket(
+                    format!("expected handshake, got {:?}", other),
+                )))
+            }
+        };
 
         if player_number != server_player_number {
-            return Err(ReversiError::ProtocolError(format!(
-                "Player number mismatch: expected {}, got {}",
-                player_number, server_player_number
+            return Err(ReversiError::ProtocolError(ProtocolError::UnexpectedPacket(
+                format!(
+                    "player number mismatch: expected {}, got {}",
+                    player_number, server_player_number
+                ),
             )));
         }
 
@@ -84,82 +102,114 @@ impl<S: ReversiStrategy> ReversiClient<S> {
             strategy,
             game_minutes,
             board: [[0; 8]; 8],
+            inbound,
         })
     }
 
     pub fn run(&mut self) -> Result<(), ReversiError> {
-        let mut buffer = [0u8; 1024];
         let mut past_4_turns = false;
         let mut num_turns = 0u8;
 
         loop {
-            let bytes_read = self.stream.read(&mut buffer)?;
-            if bytes_read == 0 {
-                return Err(ReversiError::ConnectionError("Connection closed".into()));
-            }
-
-            let message = String::from_utf8_lossy(&buffer[..bytes_read]);
-            if let Ok(state) = self.parse_message(&message) {
-                println!("Parsed state!");
-                if state.turn == self.player_number {
-                    if !past_4_turns {  // On the first 4 turns
-                        num_turns += 1; 
-                        if num_turns <= 4 {
-                            let valid_moves = self.get_valid_moves_first_4(&state.board);
-                            if !valid_moves.is_empty() {
-                                let (row, col) = self.strategy.choose_move(&valid_moves);
-                                self.send_move(row, col)?;
-                                continue;
+            match self.next_packet() {
+                Ok(Packet::GameOver) => {
+                    println!("Game over");
+                    return Ok(());
+                }
+                Ok(Packet::Handshake { .. }) => {
+                    println!("Unexpected handshake packet during play");
+                }
+                Ok(Packet::TurnState {
+                    turn,
+                    round,
+                    t1,
+                    t2,
+                    board,
+                }) => {
+                    println!("Parsed state!");
+                    self.board = board;
+                    let state = GameState {
+                        turn,
+                        round,
+                        t1,
+                        t2,
+                        board,
+                    };
+
+                    if state.turn == self.player_number {
+                        let time_budget = self.time_budget(&state);
+
+                        if !past_4_turns {
+                            // On the first 4 turns
+                            num_turns += 1;
+                            if num_turns <= 4 {
+                                let valid_moves = self.get_valid_moves_first_4(&state.board);
+                                if !valid_moves.is_empty() {
+                                    let (row, col) =
+                                        self.strategy
+                                            .choose_move(&state, &valid_moves, time_budget);
+                                    self.send_move(row, col)?;
+                                    continue;
+                                }
+                            } else {
+                                past_4_turns = true;
                             }
-                        } else {
-                            past_4_turns = true;
                         }
+
+                        let valid_moves = self.get_valid_moves(&state.board, self.player_number);
+                        let (row, col) =
+                            self.strategy
+                                .choose_move(&state, &valid_moves, time_budget);
+                        self.send_move(row, col)?;
                     }
-                
-                    let valid_moves = self.get_valid_moves(&state.board, self.player_number);
-                    let (row, col) = self.strategy.choose_move(&valid_moves);
-                    self.send_move(row, col)?;
                 }
-            } else {
-                println!("Failed to parse message");
+                Err(ReversiError::ProtocolError(err)) => {
+                    println!("Failed to parse message: {}", err);
+                }
+                Err(err) => return Err(err),
             }
         }
     }
 
-    fn parse_message(&mut self, message: &str) -> Result<GameState, ReversiError> {
-        let parts: Vec<&str> = message.split('\n').collect();
-        println!("{:?}", parts);
-        let turn=  parts[0].parse::<i32>().unwrap_or(-1);
-        if turn == -999 {
-            println!("Game over");
-            return Err(ReversiError::ProtocolError("Game over".into()));
-        }
-
-        if parts.len() < 69 {
-            return Err(ReversiError::ProtocolError("Message was too short to contain the board".into()));
-        }
+    /// Reads from the socket, buffering bytes until a complete frame is
+    /// available, and decodes it. Robust against a message being split
+    /// across multiple reads or several messages arriving in one read.
+    fn next_packet(&mut self) -> Result<Packet, ReversiError> {
+        loop {
+            if let Some(frame) = framing::next_frame(&mut self.inbound) {
+                return Ok(Packet::decode(&frame)?);
+            }
 
-        let mut board: [[i8; 8]; 8] = [[0; 8]; 8];
-        let mut index = 4;
-        for i in 0..8 {
-            for j in 0..8 {
-                board[i][j] = parts[index].parse().unwrap_or(0);
-                index += 1;
+            let mut chunk = [0u8; 1024];
+            let bytes_read = self.stream.read(&mut chunk)?;
+            if bytes_read == 0 {
+                return Err(ReversiError::ConnectionError("Connection closed".into()));
             }
+            self.inbound.extend_from_slice(&chunk[..bytes_read]);
         }
+    }
 
-        self.board = board;
-
+    /// How long the strategy has to pick this move: the remaining clock
+    /// for the side to move, divided by a rough estimate of how many moves
+    /// are left (half the empty squares). Falls back to the full game
+    /// clock if the server hasn't started counting the remaining time down
+    /// yet (e.g. on the very first turn).
+    fn time_budget(&self, state: &GameState) -> Duration {
+        let remaining_clock = if self.player_number == 1 {
+            state.t1
+        } else {
+            state.t2
+        };
+        let remaining_clock = if remaining_clock > 0.0 {
+            remaining_clock
+        } else {
+            self.game_minutes * 60.0
+        };
 
-        let small_turn = turn as i8;
+        let empty_squares = state.board.iter().flatten().filter(|&&cell| cell == 0).count();
+        let remaining_moves = (empty_squares / 2).max(1);
 
-        Ok(GameState {
-            turn: small_turn,
-            round: parts[1].parse().unwrap_or(0),
-            t1: parts[2].parse::<f32>().unwrap_or(0.0),
-            t2: parts[3].parse::<f32>().unwrap_or(0.0),
-            board,
-        })
+        Duration::from_secs_f32((remaining_clock / remaining_moves as f32).max(0.0))
     }
 
     fn get_valid_moves_first_4(&self, board: &[[i8; 8]; 8]) -> Vec<(i8, i8)> {
@@ -185,61 +235,7 @@ impl<S: ReversiStrategy> ReversiClient<S> {
     }
 
     pub fn get_valid_moves(&self, board: &[[i8; 8]; 8], player: i8) -> Vec<(i8, i8)> {
-        let mut moves: Vec<(i8, i8)> = Vec::with_capacity(24);
-        let opponent = 3 - player; // Player numbers are 1 or 2
-
-        // Directions: (dx, dy) for all 8 possible directions
-        const DIRECTIONS: [(i8, i8); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-
-        for (i, row) in board.iter().enumerate() {
-            for (j, &cell) in row.iter().enumerate() {
-                if cell != 0 {
-                    continue;
-                }
-
-                'directions: for &(dx, dy) in &DIRECTIONS {
-                    let mut x = i as i8 + dx;
-                    let mut y = j as i8 + dy;
-                    let mut found_opponent = false;
-
-                    while x >= 0 && x < 8 && y >= 0 && y < 8 {
-                        let current = board[x as usize][y as usize];
-
-                        match current {
-                            // Empty space, can't flank
-                            0 => break,
-                            // Found player's piece after opponent's
-                            p if p == player => {
-                                if found_opponent {
-                                    moves.push((i as i8, j as i8));
-                                    break 'directions;
-                                }
-                                break;
-                            }
-                            // Found opponent's piece
-                            p if p == opponent => {
-                                found_opponent = true;
-                            }
-                            _ => break,
-                        }
-
-                        x += dx;
-                        y += dy;
-                    }
-                }
-            }
-        }
-
-        moves
+        board::valid_moves(board, player)
     }
 
     fn send_move(&mut self, row: i8, col: i8) -> Result<(), ReversiError> {
@@ -268,61 +264,6 @@ mod tests {
     }
 
 
-    #[test]
-    fn test_parse_message_valid() {
-        let mut client = ReversiClient {
-            stream: create_mock_stream(),
-            player_number: 1,
-            strategy: RandomStrategy,
-            game_minutes: 0.0,
-            board: [[0; 8]; 8],
-        };
-
-        let message = "1\n0\n0.0\n0.0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n0\n";
-        let state = client.parse_message(message).unwrap();
-
-        assert_eq!(state.turn, 1);
-        assert_eq!(state.round, 0);
-        assert_eq!(state.t1, 0.0);
-        assert_eq!(state.t2, 0.0);
-        assert_eq!(state.board, [[0; 8]; 8]);
-    }
-
-    #[test]
-    fn test_parse_message_invalid() {
-        let mut client = ReversiClient {
-            stream: create_mock_stream(),
-            player_number: 1,
-            strategy: RandomStrategy,
-            game_minutes: 0.0,
-            board: [[0; 8]; 8],
-        };
-
-        let message = "invalid\nmessage\n";
-        let result = client.parse_message(message);
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_parse_message_game_over() {
-        let mut client = ReversiClient {
-            stream: create_mock_stream(),
-            player_number: 1,
-            strategy: RandomStrategy,
-            game_minutes: 0.0,
-            board: [[0; 8]; 8],
-        };
-
-        let message = "-999\n";
-        let result = client.parse_message(message);
-        if let Err(ReversiError::ProtocolError(ref e)) = result {
-            assert_eq!(e, "Game over");
-        } else {
-            panic!("Expected ProtocolError with 'Game over'");
-        }
-    }
-
     #[test]
     fn test_get_valid_moves_first_4() {
         let client = ReversiClient {
@@ -331,6 +272,7 @@ mod tests {
             strategy: RandomStrategy,
             game_minutes: 0.0,
             board: [[0; 8]; 8],
+            inbound: Vec::new(),
         };
 
         let board = [[0; 8]; 8];
@@ -347,6 +289,7 @@ mod tests {
             strategy: RandomStrategy,
             game_minutes: 0.0,
             board: [[0; 8]; 8],
+            inbound: Vec::new(),
         };
 
         let mut board = [[0; 8]; 8];
@@ -368,10 +311,34 @@ mod tests {
             strategy: RandomStrategy,
             game_minutes: 0.0,
             board: [[0; 8]; 8],
+            inbound: Vec::new(),
         };
 
         let result = client.send_move(3, 4);
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_game_state_display_renders_board() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        let state = GameState {
+            turn: 1,
+            round: 0,
+            t1: 0.0,
+            t2: 0.0,
+            board,
+        };
+
+        let rendered = state.to_string();
+
+        assert!(rendered.contains('●'));
+        assert!(rendered.contains('○'));
+        assert!(rendered.contains('·'));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,193 @@
+// reversi_client/src/protocol.rs
+//
+// The wire format spoken by the reversi server: a connection handshake, a
+// per-turn board snapshot, and a game-over sentinel. `Packet::decode` turns
+// a raw message into one of these, reporting precisely which field was
+// missing or malformed instead of silently defaulting it.
+
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ProtocolError {
+    #[error("message had {got} fields, expected at least {expected}")]
+    TooShort { got: usize, expected: usize },
+    #[error("field {line}: expected {expected}, got {value:?}")]
+    BadField {
+        line: usize,
+        expected: &'static str,
+        value: String,
+    },
+    #[error("unexpected packet: {0}")]
+    UnexpectedPacket(String),
+}
+
+const HANDSHAKE_FIELDS: usize = 2;
+const HEADER_FIELDS: usize = 4;
+const BOARD_CELLS: usize = 64;
+const TURN_STATE_FIELDS: usize = HEADER_FIELDS + BOARD_CELLS;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    /// Sent once, right after the socket connects: which player we are and
+    /// how many minutes each side has on the clock.
+    Handshake { player: i8, minutes: f32 },
+    /// Sent every turn: whose move it is, the round number, each player's
+    /// remaining time, and the full board.
+    TurnState {
+        turn: i8,
+        round: i32,
+        t1: f32,
+        t2: f32,
+        board: [[i8; 8]; 8],
+    },
+    /// The `-999` sentinel the server sends when the game has ended.
+    GameOver,
+}
+
+impl Packet {
+    /// Decodes one whitespace-delimited message into a `Packet`.
+    ///
+    /// The three packet shapes are told apart by field count: a lone
+    /// `-999` is [`Packet::GameOver`], two fields are a
+    /// [`Packet::Handshake`], and a full turn/round/clocks/board block is a
+    /// [`Packet::TurnState`].
+    pub fn decode(message: &str) -> Result<Self, ProtocolError> {
+        let fields: Vec<&str> = message.split_whitespace().collect();
+
+        if fields.is_empty() {
+            return Err(ProtocolError::TooShort {
+                got: 0,
+                expected: 1,
+            });
+        }
+
+        let turn: i32 = parse_field(fields[0], 0, "integer")?;
+        if turn == -999 {
+            return Ok(Packet::GameOver);
+        }
+
+        if fields.len() == HANDSHAKE_FIELDS {
+            let minutes = parse_field(fields[1], 1, "float")?;
+            return Ok(Packet::Handshake {
+                player: turn as i8,
+                minutes,
+            });
+        }
+
+        if fields.len() < TURN_STATE_FIELDS {
+            return Err(ProtocolError::TooShort {
+                got: fields.len(),
+                expected: TURN_STATE_FIELDS,
+            });
+        }
+
+        let round = parse_field(fields[1], 1, "integer")?;
+        let t1 = parse_field(fields[2], 2, "float")?;
+        let t2 = parse_field(fields[3], 3, "float")?;
+
+        let mut board = [[0i8; 8]; 8];
+        let mut index = HEADER_FIELDS;
+        for row in board.iter_mut() {
+            for cell in row.iter_mut() {
+                *cell = parse_field(fields[index], index, "disc (0, 1, or 2)")?;
+                index += 1;
+            }
+        }
+
+        Ok(Packet::TurnState {
+            turn: turn as i8,
+            round,
+            t1,
+            t2,
+            board,
+        })
+    }
+}
+
+fn parse_field<T: FromStr>(
+    value: &str,
+    line: usize,
+    expected: &'static str,
+) -> Result<T, ProtocolError> {
+    value.parse().map_err(|_| ProtocolError::BadField {
+        line,
+        expected,
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_handshake() {
+        let packet = Packet::decode("1 10.0").unwrap();
+
+        assert_eq!(
+            packet,
+            Packet::Handshake {
+                player: 1,
+                minutes: 10.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_turn_state() {
+        let mut message = String::from("1\n0\n0.0\n0.0\n");
+        message.push_str(&"0\n".repeat(64));
+
+        let packet = Packet::decode(&message).unwrap();
+
+        assert_eq!(
+            packet,
+            Packet::TurnState {
+                turn: 1,
+                round: 0,
+                t1: 0.0,
+                t2: 0.0,
+                board: [[0; 8]; 8],
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_game_over() {
+        let packet = Packet::decode("-999\n").unwrap();
+
+        assert_eq!(packet, Packet::GameOver);
+    }
+
+    #[test]
+    fn test_decode_too_short() {
+        let result = Packet::decode("1\n0\n0.0\n0.0\n0\n0\n");
+
+        assert_eq!(
+            result,
+            Err(ProtocolError::TooShort {
+                got: 6,
+                expected: TURN_STATE_FIELDS
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_bad_field() {
+        let mut message = String::from("1\n0\n0.0\n0.0\n");
+        message.push_str("oops\n");
+        message.push_str(&"0\n".repeat(63));
+
+        let result = Packet::decode(&message);
+
+        assert_eq!(
+            result,
+            Err(ProtocolError::BadField {
+                line: 4,
+                expected: "disc (0, 1, or 2)",
+                value: "oops".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,368 @@
+// reversi_client/src/strategy.rs
+//
+// `ReversiStrategy` implementations: a random baseline, alpha-beta search
+// engines, and an interactive human player.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::board::{self, apply_move, valid_moves};
+use crate::GameState;
+
+pub trait ReversiStrategy {
+    /// Picks a move from `valid_moves` given the current `state`. `state`
+    /// is only ever passed in for the side whose turn it is, so
+    /// `state.turn` is the strategy's own player number. `time_budget` is
+    /// how long the strategy has to decide before the client should send a
+    /// move back to the server.
+    fn choose_move(
+        &self,
+        state: &GameState,
+        valid_moves: &[(i8, i8)],
+        time_budget: Duration,
+    ) -> (i8, i8);
+}
+
+pub struct RandomStrategy;
+
+impl ReversiStrategy for RandomStrategy {
+    fn choose_move(
+        &self,
+        _state: &GameState,
+        valid_moves: &[(i8, i8)],
+        _time_budget: Duration,
+    ) -> (i8, i8) {
+        let mut rng = rand::thread_rng();
+        valid_moves[rng.gen_range(0..valid_moves.len())]
+    }
+}
+
+/// Positional weight for each square, used by [`AlphaBetaStrategy`]'s leaf
+/// evaluation. Corners are strong, and the squares diagonally and
+/// orthogonally adjacent to them (the classic "X" and "C" squares) are
+/// penalized because playing there tends to hand the corner to the
+/// opponent.
+#[rustfmt::skip]
+const WEIGHTS: [[i32; 8]; 8] = [
+    [100, -20, 10,  5,  5, 10, -20, 100],
+    [-20, -50, -2, -2, -2, -2, -50, -20],
+    [ 10,  -2,  5,  1,  1,  5,  -2,  10],
+    [  5,  -2,  1,  1,  1,  1,  -2,   5],
+    [  5,  -2,  1,  1,  1,  1,  -2,   5],
+    [ 10,  -2,  5,  1,  1,  5,  -2,  10],
+    [-20, -50, -2, -2, -2, -2, -50, -20],
+    [100, -20, 10,  5,  5, 10, -20, 100],
+];
+
+/// At or below this many empty squares the game is close enough to over that raw
+/// disc count is a better signal than positional weighting.
+const ENDGAME_EMPTY_THRESHOLD: usize = 8;
+
+/// Scores `board` from `player`'s perspective: positive is good for
+/// `player`. Combines the static positional weights with a mobility term
+/// (the difference in legal move counts), falling back to pure disc count
+/// once the board is nearly full.
+fn evaluate(board: &[[i8; 8]; 8], player: i8) -> i32 {
+    let opponent = 3 - player;
+    let empty = board.iter().flatten().filter(|&&cell| cell == 0).count();
+
+    if empty <= ENDGAME_EMPTY_THRESHOLD {
+        let mine = board.iter().flatten().filter(|&&cell| cell == player).count() as i32;
+        let theirs = board
+            .iter()
+            .flatten()
+            .filter(|&&cell| cell == opponent)
+            .count() as i32;
+        return (mine - theirs) * 100;
+    }
+
+    let mut positional = 0;
+    for (i, row) in board.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell == player {
+                positional += WEIGHTS[i][j];
+            } else if cell == opponent {
+                positional -= WEIGHTS[i][j];
+            }
+        }
+    }
+
+    let mobility = valid_moves(board, player).len() as i32 - valid_moves(board, opponent).len() as i32;
+
+    positional + mobility * 10
+}
+
+/// Negamax with alpha-beta pruning. `passed` tracks whether the previous
+/// ply was a pass (the opponent had no legal moves), so that two consecutive passes
+/// (no legal moves for either side) end the search instead of recursing
+/// forever.
+fn negamax(
+    board: &[[i8; 8]; 8],
+    player: i8,
+    depth: u32,
+    mut alpha: i32,
+    beta: i32,
+    passed: bool,
+) -> i32 {
+    let moves = valid_moves(board, player);
+
+    if moves.is_empty() {
+        if passed {
+            return evaluate(board, player);
+        }
+        // No legal moves: pass the turn without spending a ply of depth.
+        return -negamax(board, 3 - player, depth, -beta, -alpha, true);
+    }
+
+    if depth == 0 {
+        return evaluate(board, player);
+    }
+
+    let mut best = i32::MIN + 1;
+    for mv in moves {
+        let mut child = *board;
+        apply_move(&mut child, player, mv);
+        let score = -negamax(&child, 3 - player, depth - 1, -beta, -alpha, false);
+
+        if score > best {
+            best = score;
+        }
+        if best > alpha {
+            alpha = best;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    best
+}
+
+/// Runs one fixed-depth negamax search from `board` and returns the best of
+/// `valid_moves` for `player`. Shared by [`AlphaBetaStrategy`] and
+/// [`IterativeDeepeningStrategy`], which just calls this at increasing
+/// depths.
+fn root_search(board: &[[i8; 8]; 8], player: i8, valid_moves: &[(i8, i8)], depth: u32) -> (i8, i8) {
+    let opponent = 3 - player;
+    let (mut alpha, beta) = (i32::MIN + 1, i32::MAX - 1);
+
+    let mut best_move = valid_moves[0];
+    let mut best_score = i32::MIN;
+
+    for &mv in valid_moves {
+        let mut child = *board;
+        apply_move(&mut child, player, mv);
+        let score = -negamax(&child, opponent, depth.saturating_sub(1), -beta, -alpha, false);
+
+        if score > best_score {
+            best_score = score;
+            best_move = mv;
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_move
+}
+
+/// A fixed-depth negamax search with alpha-beta pruning over the board
+/// logic in `board`.
+pub struct AlphaBetaStrategy {
+    pub depth: u32,
+}
+
+impl AlphaBetaStrategy {
+    pub fn new(depth: u32) -> Self {
+        Self { depth }
+    }
+}
+
+impl ReversiStrategy for AlphaBetaStrategy {
+    fn choose_move(
+        &self,
+        state: &GameState,
+        valid_moves: &[(i8, i8)],
+        _time_budget: Duration,
+    ) -> (i8, i8) {
+        root_search(&state.board, state.turn, valid_moves, self.depth)
+    }
+}
+
+/// Each ply deeper roughly multiplies the search tree (and its cost) by
+/// the branching factor; used to predict whether the next depth would fit
+/// in the remaining time budget.
+const BRANCHING_FACTOR_ESTIMATE: u32 = 6;
+
+/// Searches depth 1, then 2, 3, … using [`root_search`], keeping the best
+/// move from the deepest depth it managed to finish. Always completes at
+/// least depth 1, and estimates each next depth's cost (previous depth's
+/// elapsed time times [`BRANCHING_FACTOR_ESTIMATE`]) before starting it, so
+/// it stops short of `time_budget` instead of overshooting it mid-search.
+pub struct IterativeDeepeningStrategy {
+    pub max_depth: u32,
+}
+
+impl IterativeDeepeningStrategy {
+    pub fn new(max_depth: u32) -> Self {
+        Self { max_depth }
+    }
+}
+
+impl ReversiStrategy for IterativeDeepeningStrategy {
+    fn choose_move(
+        &self,
+        state: &GameState,
+        valid_moves: &[(i8, i8)],
+        time_budget: Duration,
+    ) -> (i8, i8) {
+        let start = Instant::now();
+        let mut best_move = valid_moves[0];
+        let mut last_depth_elapsed = Duration::ZERO;
+
+        for depth in 1..=self.max_depth {
+            let elapsed = start.elapsed();
+            if depth > 1 && elapsed >= time_budget {
+                break;
+            }
+
+            let predicted_next_depth = last_depth_elapsed * BRANCHING_FACTOR_ESTIMATE;
+            if depth > 1 && predicted_next_depth > time_budget - elapsed {
+                break;
+            }
+
+            let depth_start = Instant::now();
+            best_move = root_search(&state.board, state.turn, valid_moves, depth);
+            last_depth_elapsed = depth_start.elapsed();
+        }
+
+        best_move
+    }
+}
+
+/// Lets a person drive the client: prints the board with the candidate
+/// moves marked, then reads a `row col` coordinate from stdin, re-prompting
+/// until it gets one of `valid_moves`.
+pub struct HumanStrategy;
+
+impl ReversiStrategy for HumanStrategy {
+    fn choose_move(
+        &self,
+        state: &GameState,
+        valid_moves: &[(i8, i8)],
+        _time_budget: Duration,
+    ) -> (i8, i8) {
+        println!("{}", board::render(&state.board, valid_moves));
+        println!("Valid moves: {:?}", valid_moves);
+
+        loop {
+            print!("Enter your move as 'row col': ");
+            let _ = io::stdout().flush();
+
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input).is_err() {
+                println!("Failed to read input, try again.");
+                continue;
+            }
+
+            let fields: Vec<&str> = input.split_whitespace().collect();
+            let mv = match fields.as_slice() {
+                [row, col] => row.parse::<i8>().ok().zip(col.parse::<i8>().ok()),
+                _ => None,
+            };
+
+            match mv {
+                Some(mv) if valid_moves.contains(&mv) => return mv,
+                _ => println!("That's not a legal move, try again."),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_board(board: [[i8; 8]; 8], turn: i8) -> GameState {
+        GameState {
+            turn,
+            round: 0,
+            t1: 0.0,
+            t2: 0.0,
+            board,
+        }
+    }
+
+    #[test]
+    fn test_alpha_beta_takes_only_legal_move() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        let moves = valid_moves(&board, 1);
+        let state = state_with_board(board, 1);
+        let strategy = AlphaBetaStrategy::new(3);
+
+        let mv = strategy.choose_move(&state, &moves, Duration::from_secs(1));
+
+        assert!(moves.contains(&mv));
+    }
+
+    #[test]
+    fn test_alpha_beta_prefers_corner() {
+        // Two otherwise-equivalent flanking moves, one landing on a
+        // corner; the positional weight should make the corner win.
+        let mut board = [[0; 8]; 8];
+        board[0][1] = 2;
+        board[0][2] = 1;
+        board[3][4] = 2;
+        board[3][5] = 1;
+
+        let moves = valid_moves(&board, 1);
+        let state = state_with_board(board, 1);
+        let strategy = AlphaBetaStrategy::new(2);
+
+        let mv = strategy.choose_move(&state, &moves, Duration::from_secs(1));
+
+        assert_eq!(mv, (0, 0));
+    }
+
+    #[test]
+    fn test_iterative_deepening_completes_at_least_depth_one() {
+        let mut board = [[0; 8]; 8];
+        board[3][3] = 2;
+        board[3][4] = 1;
+        board[4][3] = 1;
+        board[4][4] = 2;
+
+        let moves = valid_moves(&board, 1);
+        let state = state_with_board(board, 1);
+        let strategy = IterativeDeepeningStrategy::new(10);
+
+        // A near-zero budget still finishes depth 1 before the first check.
+        let mv = strategy.choose_move(&state, &moves, Duration::from_nanos(1));
+
+        assert!(moves.contains(&mv));
+    }
+
+    #[test]
+    fn test_iterative_deepening_prefers_corner_given_time() {
+        let mut board = [[0; 8]; 8];
+        board[0][1] = 2;
+        board[0][2] = 1;
+        board[3][4] = 2;
+        board[3][5] = 1;
+
+        let moves = valid_moves(&board, 1);
+        let state = state_with_board(board, 1);
+        let strategy = IterativeDeepeningStrategy::new(3);
+
+        let mv = strategy.choose_move(&state, &moves, Duration::from_secs(1));
+
+        assert_eq!(mv, (0, 0));
+    }
+}